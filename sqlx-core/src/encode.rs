@@ -2,9 +2,10 @@
 
 use std::borrow::Cow;
 use std::mem;
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
 
 use crate::database::{Database, HasArguments};
+use crate::error::BoxDynError;
 
 /// The return type of [Encode::encode].
 pub enum IsNull {
@@ -17,23 +18,107 @@ pub enum IsNull {
     No,
 }
 
+/// Resolves database-specific type metadata by runtime type name at bind time.
+///
+/// Drivers that support runtime-registered composite/enum/array types implement this so
+/// value-dependent encoders can resolve the correct wire metadata (e.g. a Postgres OID) for
+/// a type that is only known by name. Drivers that don't need it never construct one.
+pub trait MetadataLookup<DB: Database> {
+    /// Looks up the [`TypeInfo`] registered under `name`, if any.
+    ///
+    /// [`TypeInfo`]: Database::TypeInfo
+    fn lookup(&mut self, name: &str) -> Option<DB::TypeInfo>;
+}
+
+/// The context passed to [`Encode::encode`] and [`Encode::encode_by_ref`].
+///
+/// Wraps the argument buffer together with an optional [`MetadataLookup`] handle, so that
+/// value-dependent encoders can resolve runtime type metadata while writing. Encoders that
+/// only need to write bytes can reach the buffer directly through [`Deref`]/[`DerefMut`] or
+/// the [`buffer`](Self::buffer)/[`buffer_mut`](Self::buffer_mut) accessors and ignore the
+/// lookup entirely.
+pub struct EncodeContext<'a, 'q, DB: Database> {
+    buf: &'a mut <DB as HasArguments<'q>>::ArgumentBuffer,
+    metadata: Option<&'a mut (dyn MetadataLookup<DB> + 'a)>,
+}
+
+impl<'a, 'q, DB: Database> EncodeContext<'a, 'q, DB> {
+    /// Creates a context over `buf` with no metadata lookup available.
+    pub fn new(buf: &'a mut <DB as HasArguments<'q>>::ArgumentBuffer) -> Self {
+        EncodeContext {
+            buf,
+            metadata: None,
+        }
+    }
+
+    /// Creates a context over `buf` backed by the given metadata lookup.
+    pub fn with_metadata(
+        buf: &'a mut <DB as HasArguments<'q>>::ArgumentBuffer,
+        metadata: &'a mut (dyn MetadataLookup<DB> + 'a),
+    ) -> Self {
+        EncodeContext {
+            buf,
+            metadata: Some(metadata),
+        }
+    }
+
+    /// Returns a shared reference to the argument buffer.
+    pub fn buffer(&self) -> &<DB as HasArguments<'q>>::ArgumentBuffer {
+        self.buf
+    }
+
+    /// Returns a mutable reference to the argument buffer.
+    pub fn buffer_mut(&mut self) -> &mut <DB as HasArguments<'q>>::ArgumentBuffer {
+        self.buf
+    }
+
+    /// Returns the metadata lookup, if one is available in this context.
+    pub fn metadata(&mut self) -> Option<&mut (dyn MetadataLookup<DB> + 'a)> {
+        self.metadata.as_deref_mut()
+    }
+
+    /// Reborrows the context so it can be handed to a nested field encoder.
+    pub fn reborrow(&mut self) -> EncodeContext<'_, 'q, DB> {
+        EncodeContext {
+            buf: self.buf,
+            metadata: self.metadata.as_deref_mut(),
+        }
+    }
+}
+
+impl<'a, 'q, DB: Database> Deref for EncodeContext<'a, 'q, DB> {
+    type Target = <DB as HasArguments<'q>>::ArgumentBuffer;
+
+    fn deref(&self) -> &Self::Target {
+        self.buf
+    }
+}
+
+impl<'a, 'q, DB: Database> DerefMut for EncodeContext<'a, 'q, DB> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buf
+    }
+}
+
 /// Encode a single value to be sent to the database.
 pub trait Encode<'q, DB: Database> {
-    /// Writes the value of `self` into `buf` in the expected format for the database.
-    #[must_use]
-    fn encode(self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull
+    /// Writes the value of `self` into `ctx` in the expected format for the database.
+    ///
+    /// Returns an error if the value cannot be represented in that format (e.g. an
+    /// out-of-range numeric or a composite whose field encoder fails); the driver
+    /// surfaces it as a bind-time error rather than corrupting the wire protocol.
+    fn encode(self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError>
     where
         Self: Sized,
     {
-        self.encode_by_ref(buf)
+        self.encode_by_ref(ctx)
     }
 
-    /// Writes the value of `self` into `buf` without moving `self`.
+    /// Writes the value of `self` into `ctx` without moving `self`.
     ///
     /// Where possible, make use of `encode` instead as it can take advantage of re-using
     /// memory.
-    #[must_use]
-    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull;
+    fn encode_by_ref(&self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError>;
 
     fn produces(&self) -> Option<DB::TypeInfo> {
         // `produces` is inherently a hook to allow database drivers to produce value-dependent
@@ -52,13 +137,13 @@ where
     T: Encode<'q, DB>,
 {
     #[inline]
-    fn encode(self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
-        <T as Encode<DB>>::encode_by_ref(self, buf)
+    fn encode(self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError> {
+        <T as Encode<DB>>::encode_by_ref(self, ctx)
     }
 
     #[inline]
-    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
-        <&T as Encode<DB>>::encode(self, buf)
+    fn encode_by_ref(&self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError> {
+        <&T as Encode<DB>>::encode(self, ctx)
     }
 
     #[inline]
@@ -91,24 +176,24 @@ macro_rules! impl_encode_for_option {
             #[inline]
             fn encode(
                 self,
-                buf: &mut <$DB as $crate::database::HasArguments<'q>>::ArgumentBuffer,
-            ) -> $crate::encode::IsNull {
+                ctx: &mut $crate::encode::EncodeContext<'_, 'q, $DB>,
+            ) -> Result<$crate::encode::IsNull, $crate::error::BoxDynError> {
                 if let Some(v) = self {
-                    v.encode(buf)
+                    v.encode(ctx)
                 } else {
-                    $crate::encode::IsNull::Yes
+                    Ok($crate::encode::IsNull::Yes)
                 }
             }
 
             #[inline]
             fn encode_by_ref(
                 &self,
-                buf: &mut <$DB as $crate::database::HasArguments<'q>>::ArgumentBuffer,
-            ) -> $crate::encode::IsNull {
+                ctx: &mut $crate::encode::EncodeContext<'_, 'q, $DB>,
+            ) -> Result<$crate::encode::IsNull, $crate::error::BoxDynError> {
                 if let Some(v) = self {
-                    v.encode_by_ref(buf)
+                    v.encode_by_ref(ctx)
                 } else {
-                    $crate::encode::IsNull::Yes
+                    Ok($crate::encode::IsNull::Yes)
                 }
             }
 
@@ -126,13 +211,13 @@ where
     T: Encode<'q, DB>,
 {
     #[inline]
-    fn encode(self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
-        <T as Encode<'q, DB>>::encode_by_ref(self.deref(), buf)
+    fn encode(self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError> {
+        <T as Encode<'q, DB>>::encode_by_ref(self.deref(), ctx)
     }
 
     #[inline]
-    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
-        <T as Encode<'q, DB>>::encode_by_ref(self, buf)
+    fn encode_by_ref(&self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError> {
+        <T as Encode<'q, DB>>::encode_by_ref(self, ctx)
     }
 
     #[inline]
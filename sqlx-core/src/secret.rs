@@ -0,0 +1,170 @@
+//! Provides [`Secret`] for binding sensitive values without leaking them into logs.
+
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::Deref;
+
+use crate::database::{Database, HasArguments};
+use crate::encode::{Encode, EncodeContext, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+/// Per-argument bookkeeping that lets [`Secret`] redact sensitive bound values from query logs.
+///
+/// A database's [`ArgumentBuffer`] implements this so that [`Secret`] can flag the argument it
+/// is currently encoding as sensitive. The driver's `Arguments` formatting path — the one that
+/// renders the `log::debug!` query-parameter string — consults [`is_sensitive`] for each
+/// argument in bind order and substitutes [`REDACTED`] for the real value. This is what keeps a
+/// wrapped password or token out of the logs; the [`Debug`]/[`Display`] impls below only guard
+/// the rare direct-formatting case, since logging never formats the `Secret` wrapper itself.
+///
+/// # Ownership of the current-argument index
+///
+/// The `ArgumentBuffer` owns the bind position. Each `Arguments::add` encodes exactly one
+/// bound value into the buffer and is the single place a new argument boundary is opened, so
+/// "the argument currently being encoded" is unambiguous: it is the value that enclosing
+/// `add` is committing. [`set_sensitive`] flags *that* argument, regardless of how many bytes
+/// (or how many nested fields via [`WriteTuple`](crate::write_tuple::WriteTuple)) its encoder
+/// writes — an inner encoder never opens a new top-level argument, so the flag can't be
+/// misattributed. The buffer records the flag against the position and advances the position
+/// only when `add` returns; [`is_sensitive`] then reads it back by bind order.
+///
+/// [`ArgumentBuffer`]: crate::database::HasArguments::ArgumentBuffer
+/// [`set_sensitive`]: SensitiveArguments::set_sensitive
+/// [`is_sensitive`]: SensitiveArguments::is_sensitive
+/// [`REDACTED`]: SensitiveArguments::REDACTED
+pub trait SensitiveArguments {
+    /// The placeholder written to the query log in place of a redacted value.
+    const REDACTED: &'static str = "[redacted]";
+
+    /// Flags the argument currently being encoded — the one the enclosing `Arguments::add`
+    /// is committing — as sensitive.
+    fn set_sensitive(&mut self);
+
+    /// Returns whether the argument at `index` (in bind order) was flagged sensitive.
+    fn is_sensitive(&self, index: usize) -> bool;
+}
+
+/// A wrapper around a bound value that must never appear in logs.
+///
+/// `Secret<T>` encodes transparently as the inner `T` — [`Encode`], [`produces`], and
+/// [`size_hint`] all delegate straight through — but its [`Debug`]/[`Display`] print
+/// `[redacted]`, and the query-log / `log::debug!` parameter-formatting path replaces the
+/// value with [`SensitiveArguments::REDACTED`] instead of the real bytes. Use it to bind
+/// passwords, tokens, and other PII without leaking them.
+///
+/// [`produces`]: Encode::produces
+/// [`size_hint`]: Encode::size_hint
+pub struct Secret<T>(T);
+
+/// Alias for [`Secret`] for users who prefer the "redacted" spelling.
+pub type Redacted<T> = Secret<T>;
+
+impl<T> Secret<T> {
+    /// Wraps `value` so that it is redacted from logs while still encoding normally.
+    pub const fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    /// Unwraps the secret, returning the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Returns a shared reference to the inner value.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+impl<T> Deref for Secret<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Debug for Secret<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("[redacted]")
+    }
+}
+
+impl<T> Display for Secret<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("[redacted]")
+    }
+}
+
+impl<'q, T, DB: Database> Encode<'q, DB> for Secret<T>
+where
+    T: Encode<'q, DB>,
+    <DB as HasArguments<'q>>::ArgumentBuffer: SensitiveArguments,
+{
+    #[inline]
+    fn encode(self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError> {
+        ctx.buffer_mut().set_sensitive();
+        self.0.encode(ctx)
+    }
+
+    #[inline]
+    fn encode_by_ref(&self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError> {
+        ctx.buffer_mut().set_sensitive();
+        self.0.encode_by_ref(ctx)
+    }
+
+    #[inline]
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        self.0.produces()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl<T, DB: Database> Type<DB> for Secret<T>
+where
+    T: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Secret<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Secret<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Secret)
+    }
+}
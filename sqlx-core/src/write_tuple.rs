@@ -0,0 +1,101 @@
+//! Provides [`WriteTuple`] for encoding Rust tuples as database composite/record values.
+
+use crate::database::Database;
+use crate::encode::{Encode, EncodeContext, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+/// A sink for writing the fields of a composite/record value in order.
+///
+/// Backends that support composite types (notably Postgres) implement this on the
+/// [`EncodeContext`], so each field encoder sees the full context — buffer *and*
+/// [`MetadataLookup`] — and a nested composite/enum/array field can resolve its OID-by-name
+/// while writing. Backends without composite support simply leave it unimplemented, so the
+/// blanket [`WriteTuple`] impls don't resolve for them.
+///
+/// [`MetadataLookup`]: crate::encode::MetadataLookup
+pub trait RecordEncoder<'q, DB: Database> {
+    /// Writes the header announcing that `len` fields follow.
+    fn field_count(&mut self, len: usize) -> Result<(), BoxDynError>;
+
+    /// Writes a single field: its resolved type OID followed by the encoded payload.
+    ///
+    /// The OID is taken from [`Encode::produces`] when the value carries value-dependent type
+    /// information, falling back to [`Type::type_info`]. An [`IsNull::Yes`] result is written
+    /// as the backend's null sentinel rather than an empty payload. The field is encoded
+    /// through this context, so its own encoder keeps access to the metadata lookup.
+    fn encode_field<T>(&mut self, value: &T) -> Result<(), BoxDynError>
+    where
+        T: Encode<'q, DB> + Type<DB>;
+}
+
+/// Encodes a Rust tuple as a database composite/record value.
+///
+/// Blanket impls cover tuples up to arity 16 where every element is [`Encode`] + [`Type`];
+/// each element contributes its `produces()`/`type_info()` OID and the bytes from
+/// `encode_by_ref`, with `IsNull::Yes` elements written as the null sentinel. This gives
+/// derived struct mapping and ad-hoc tuple binding a single shared encoding path instead of
+/// hand-rolled `PgRecordEncoder` calls.
+pub trait WriteTuple<'q, DB: Database> {
+    /// Writes the composite value into `ctx`.
+    fn write_tuple(&self, ctx: &mut EncodeContext<'_, 'q, DB>) -> Result<IsNull, BoxDynError>;
+}
+
+macro_rules! count_idents {
+    () => (0usize);
+    ($head:ident $($tail:ident)*) => (1usize + count_idents!($($tail)*));
+}
+
+macro_rules! impl_write_tuple {
+    ($($idx:tt : $T:ident),+ $(,)?) => {
+        impl<'q, DB, $($T),+> WriteTuple<'q, DB> for ($($T,)+)
+        where
+            DB: Database,
+            for<'a> EncodeContext<'a, 'q, DB>: RecordEncoder<'q, DB>,
+            $($T: Encode<'q, DB> + Type<DB>,)+
+        {
+            fn write_tuple(
+                &self,
+                ctx: &mut EncodeContext<'_, 'q, DB>,
+            ) -> Result<IsNull, BoxDynError> {
+                ctx.field_count(count_idents!($($T)+))?;
+                $( ctx.encode_field(&self.$idx)?; )+
+                Ok(IsNull::No)
+            }
+        }
+
+        // Bridge `WriteTuple` into `Encode` so `query(..).bind(tuple)` binds a tuple as a
+        // composite value without the caller reaching for a backend record encoder.
+        impl<'q, DB, $($T),+> Encode<'q, DB> for ($($T,)+)
+        where
+            DB: Database,
+            for<'a> EncodeContext<'a, 'q, DB>: RecordEncoder<'q, DB>,
+            $($T: Encode<'q, DB> + Type<DB>,)+
+        {
+            #[inline]
+            fn encode_by_ref(
+                &self,
+                ctx: &mut EncodeContext<'_, 'q, DB>,
+            ) -> Result<IsNull, BoxDynError> {
+                <Self as WriteTuple<'q, DB>>::write_tuple(self, ctx)
+            }
+        }
+    };
+}
+
+impl_write_tuple!(0: T0);
+impl_write_tuple!(0: T0, 1: T1);
+impl_write_tuple!(0: T0, 1: T1, 2: T2);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8, 9: T9);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8, 9: T9, 10: T10);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8, 9: T9, 10: T10, 11: T11);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8, 9: T9, 10: T10, 11: T11, 12: T12);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8, 9: T9, 10: T10, 11: T11, 12: T12, 13: T13);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8, 9: T9, 10: T10, 11: T11, 12: T12, 13: T13, 14: T14);
+impl_write_tuple!(0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8, 9: T9, 10: T10, 11: T11, 12: T12, 13: T13, 14: T14, 15: T15);